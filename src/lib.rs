@@ -0,0 +1,691 @@
+//! # rate-conv
+//!
+//! The conversion engine behind the `rateconv` CLI, exposed as a standalone library so
+//! other crates can parse, convert, and combine data rates without shelling out.
+//!
+//! ```
+//! use rate_conv::{DataRate, DataSizeUnit, TimeUnit};
+//! use std::str::FromStr;
+//!
+//! let rate: DataRate = "100 kb/s".parse().unwrap();
+//! let target = DataRate::new(0.0, DataSizeUnit::MegaBit, TimeUnit::Hour);
+//! let converted = rate.convert_to(&target);
+//! assert_eq!(converted.size_unit(), DataSizeUnit::MegaBit);
+//! ```
+use core::fmt;
+use nom::{
+    bytes::complete::take_while,
+    combinator::map_res,
+    error::{FromExternalError, ParseError},
+    number::complete::double,
+    sequence::tuple,
+    IResult,
+};
+use std::{
+    num::{ParseFloatError, ParseIntError},
+    ops::{Add, AddAssign, Mul, MulAssign},
+    str::FromStr,
+};
+
+/// Re-exported so callers can match on `ConverterError::NomError`'s `ErrorKind` without
+/// adding their own, version-matched `nom` dependency.
+pub use nom::error::ErrorKind;
+
+/// Seconds in an average month, using the Gregorian mean of 30.4375 days
+/// (365.25 days / 12 months) rather than a fixed 30-day approximation.
+const SECONDS_PER_MONTH: f64 = 30.4375 * 3600.0 * 24.0;
+/// Seconds in an average year, accounting for the extra quarter-day from leap years.
+const SECONDS_PER_YEAR: f64 = 365.25 * 3600.0 * 24.0;
+
+/// Represents a time unit, such as seconds, milliseconds, or hours
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TimeUnit {
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl FromStr for TimeUnit {
+    type Err = ConverterError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ms" => Ok(TimeUnit::Millisecond),
+            "s" | "sec" | "second" => Ok(TimeUnit::Second),
+            "m" | "min" => Ok(TimeUnit::Minute),
+            "h" | "hr" | "hour" => Ok(TimeUnit::Hour),
+            "d" | "day" => Ok(TimeUnit::Day),
+            "w" | "wk" | "week" => Ok(TimeUnit::Week),
+            "mo" | "month" => Ok(TimeUnit::Month),
+            "y" | "yr" | "year" => Ok(TimeUnit::Year),
+            _ => Err(ConverterError::TimeUnitParseError(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let base_unit = match self {
+            TimeUnit::Millisecond => "millisecond",
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Month => "month",
+            TimeUnit::Year => "year",
+        };
+        write!(f, "{}", base_unit)
+    }
+}
+
+impl TimeUnit {
+    pub fn convert_to_bits_per_second(&self, value: f64) -> f64 {
+        match self {
+            TimeUnit::Millisecond => value * 1000.0,
+            TimeUnit::Second => value,
+            TimeUnit::Minute => value / 60.0,
+            TimeUnit::Hour => value / 3600.0,
+            TimeUnit::Day => value / (3600.0 * 24.0),
+            TimeUnit::Week => value / (3600.0 * 24.0 * 7.0),
+            TimeUnit::Month => value / SECONDS_PER_MONTH,
+            TimeUnit::Year => value / SECONDS_PER_YEAR,
+        }
+    }
+
+    pub fn convert_from_bits_per_second(&self, value: f64) -> f64 {
+        match self {
+            TimeUnit::Millisecond => value / 1000.0,
+            TimeUnit::Second => value,
+            TimeUnit::Minute => value * 60.0,
+            TimeUnit::Hour => value * 3600.0,
+            TimeUnit::Day => value * (3600.0 * 24.0),
+            TimeUnit::Week => value * (3600.0 * 24.0 * 7.0),
+            TimeUnit::Month => value * SECONDS_PER_MONTH,
+            TimeUnit::Year => value * SECONDS_PER_YEAR,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DataSizeUnit {
+    Bit,
+    KiloBit,
+    MegaBit,
+    GigaBit,
+    TeraBit,
+    PetaBit,
+    ExaBit,
+    Byte,
+    KiloByte,
+    MegaByte,
+    GigaByte,
+    TeraByte,
+    PetaByte,
+    ExaByte,
+    KibiByte,
+    MebiByte,
+    GibiByte,
+    TebiByte,
+    PebiByte,
+    ExbiByte,
+}
+
+impl FromStr for DataSizeUnit {
+    type Err = ConverterError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "b" | "bits" | "Bits" => Ok(DataSizeUnit::Bit),
+            "kb" | "kbits" | "KBits" => Ok(DataSizeUnit::KiloBit),
+            "mb" | "mbits" | "MBits" => Ok(DataSizeUnit::MegaBit),
+            "gb" | "gbits" | "GBits" => Ok(DataSizeUnit::GigaBit),
+            "tb" | "tbits" | "TBits" => Ok(DataSizeUnit::TeraBit),
+            "pb" | "pbits" | "PBits" => Ok(DataSizeUnit::PetaBit),
+            "eb" | "ebits" | "EBits" => Ok(DataSizeUnit::ExaBit),
+            "B" | "bytes" => Ok(DataSizeUnit::Byte),
+            "kB" | "KB" | "kBytes" | "KBytes" => Ok(DataSizeUnit::KiloByte),
+            "mB" | "MB" | "mBytes" | "MBytes" => Ok(DataSizeUnit::MegaByte),
+            "gB" | "GB" | "gBytes" | "GBytes" => Ok(DataSizeUnit::GigaByte),
+            "tB" | "TB" | "tBytes" | "TBytes" => Ok(DataSizeUnit::TeraByte),
+            "pB" | "PB" | "pBytes" | "PBytes" => Ok(DataSizeUnit::PetaByte),
+            "eB" | "EB" | "eBytes" | "EBytes" => Ok(DataSizeUnit::ExaByte),
+            "kiB" | "KiB" | "kibiBytes" | "KibiBytes" => Ok(DataSizeUnit::KibiByte),
+            "miB" | "MiB" | "mebiBytes" | "MebiBytes" => Ok(DataSizeUnit::MebiByte),
+            "giB" | "GiB" | "gibiBytes" | "GibiBytes" => Ok(DataSizeUnit::GibiByte),
+            "tiB" | "TiB" | "tebiBytes" | "TebiBytes" => Ok(DataSizeUnit::TebiByte),
+            "piB" | "PiB" | "pebiBytes" | "PebiBytes" => Ok(DataSizeUnit::PebiByte),
+            "eiB" | "EiB" | "exbiBytes" | "ExbiBytes" => Ok(DataSizeUnit::ExbiByte),
+            _ => Err(ConverterError::DataSizeUnitParseError(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for DataSizeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let base_unit = match self {
+            DataSizeUnit::Bit => "bit",
+            DataSizeUnit::KiloBit => "kilobit",
+            DataSizeUnit::MegaBit => "megabit",
+            DataSizeUnit::GigaBit => "gigabit",
+            DataSizeUnit::TeraBit => "terabit",
+            DataSizeUnit::PetaBit => "petabit",
+            DataSizeUnit::ExaBit => "exabit",
+            DataSizeUnit::Byte => "byte",
+            DataSizeUnit::KiloByte => "kilobyte",
+            DataSizeUnit::MegaByte => "megabyte",
+            DataSizeUnit::GigaByte => "gigabyte",
+            DataSizeUnit::TeraByte => "terabyte",
+            DataSizeUnit::PetaByte => "petabyte",
+            DataSizeUnit::ExaByte => "exabyte",
+            DataSizeUnit::KibiByte => "kibibyte",
+            DataSizeUnit::MebiByte => "mebibyte",
+            DataSizeUnit::GibiByte => "gibibyte",
+            DataSizeUnit::TebiByte => "tebibyte",
+            DataSizeUnit::PebiByte => "pebibyte",
+            DataSizeUnit::ExbiByte => "exbibyte",
+        };
+        write!(f, "{}", base_unit)
+    }
+}
+
+impl DataSizeUnit {
+    pub fn convert_to_bits(&self, value: f64) -> f64 {
+        match self {
+            DataSizeUnit::Bit => value,
+            DataSizeUnit::KiloBit => value * 1_000.0,
+            DataSizeUnit::MegaBit => value * 1_000_000.0,
+            DataSizeUnit::GigaBit => value * 1_000_000_000.0,
+            DataSizeUnit::TeraBit => value * 1_000_000_000_000.0,
+            DataSizeUnit::PetaBit => value * 1_000_000_000_000_000.0,
+            DataSizeUnit::ExaBit => value * 1_000_000_000_000_000_000.0,
+            DataSizeUnit::Byte => value * 8.0,
+            DataSizeUnit::KiloByte => value * 8_000.0,
+            DataSizeUnit::MegaByte => value * 8_000_000.0,
+            DataSizeUnit::GigaByte => value * 8_000_000_000.0,
+            DataSizeUnit::TeraByte => value * 8_000_000_000_000.0,
+            DataSizeUnit::PetaByte => value * 8_000_000_000_000_000.0,
+            DataSizeUnit::ExaByte => value * 8_000_000_000_000_000_000.0,
+            DataSizeUnit::KibiByte => value * (8 * 1024) as f64,
+            DataSizeUnit::MebiByte => value * (8 * 1024 * 1024) as f64,
+            DataSizeUnit::GibiByte => value * (8f64 * 1024f64 * 1024f64 * 1024f64),
+            DataSizeUnit::TebiByte => value * (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64),
+            DataSizeUnit::PebiByte => {
+                value * (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64)
+            }
+            DataSizeUnit::ExbiByte => {
+                value * (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64)
+            }
+        }
+    }
+
+    pub fn convert_from_bits(&self, value: f64) -> f64 {
+        match self {
+            DataSizeUnit::Bit => value,
+            DataSizeUnit::KiloBit => value / 1_000.0,
+            DataSizeUnit::MegaBit => value / 1_000_000.0,
+            DataSizeUnit::GigaBit => value / 1_000_000_000.0,
+            DataSizeUnit::TeraBit => value / 1_000_000_000_000.0,
+            DataSizeUnit::PetaBit => value / 1_000_000_000_000_000.0,
+            DataSizeUnit::ExaBit => value / 1_000_000_000_000_000_000.0,
+            DataSizeUnit::Byte => value / 8.0,
+            DataSizeUnit::KiloByte => value / 8_000.0,
+            DataSizeUnit::MegaByte => value / 8_000_000.0,
+            DataSizeUnit::GigaByte => value / 8_000_000_000.0,
+            DataSizeUnit::TeraByte => value / 8_000_000_000_000.0,
+            DataSizeUnit::PetaByte => value / 8_000_000_000_000_000.0,
+            DataSizeUnit::ExaByte => value / 8_000_000_000_000_000_000.0,
+            DataSizeUnit::KibiByte => value / (8 * 1024) as f64,
+            DataSizeUnit::MebiByte => value / (8 * 1024 * 1024) as f64,
+            DataSizeUnit::GibiByte => value / (8f64 * 1024f64 * 1024f64 * 1024f64),
+            DataSizeUnit::TebiByte => value / (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64),
+            DataSizeUnit::PebiByte => {
+                value / (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64)
+            }
+            DataSizeUnit::ExbiByte => {
+                value / (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64)
+            }
+        }
+    }
+}
+
+/// Describes a data rate: a quantity expressed in a given size unit per a given time unit.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DataRate {
+    quantity: f64,
+    size_unit: DataSizeUnit,
+    time_unit: TimeUnit,
+}
+
+impl DataRate {
+    pub const fn new(quantity: f64, size_unit: DataSizeUnit, time_unit: TimeUnit) -> DataRate {
+        Self {
+            quantity,
+            size_unit,
+            time_unit,
+        }
+    }
+
+    pub fn quantity(&self) -> f64 {
+        self.quantity
+    }
+
+    pub fn size_unit(&self) -> DataSizeUnit {
+        self.size_unit
+    }
+
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+
+    /// Converts this rate into the size/time units of `target`; `target`'s own
+    /// quantity is ignored, only its units are used as the conversion destination.
+    pub fn convert_to(&self, target: &DataRate) -> DataRate {
+        let quantity =
+            convert_data_rate(self, target).expect("rate conversion is always defined");
+        DataRate::new(quantity, target.size_unit, target.time_unit)
+    }
+}
+
+impl FromStr for DataRate {
+    type Err = ConverterError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, rate) = unwrap_nom_error(parse_input_rate(s))?;
+        Ok(rate)
+    }
+}
+
+impl fmt::Display for DataRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(
+                f,
+                "{:.precision$} {:?}/{:?}",
+                self.quantity, self.size_unit, self.time_unit
+            ),
+            None => write!(f, "{} {:?}/{:?}", self.quantity, self.size_unit, self.time_unit),
+        }
+    }
+}
+
+impl Mul<f64> for DataRate {
+    type Output = DataRate;
+    fn mul(self, rhs: f64) -> DataRate {
+        DataRate::new(self.quantity * rhs, self.size_unit, self.time_unit)
+    }
+}
+
+impl MulAssign<f64> for DataRate {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.quantity *= rhs;
+    }
+}
+
+impl Add for DataRate {
+    type Output = DataRate;
+    /// Normalizes both rates to bits/second, sums them, then expresses the result
+    /// back in `self`'s size/time units.
+    fn add(self, rhs: DataRate) -> DataRate {
+        let total_bits_per_second = self.bits_per_second() + rhs.bits_per_second();
+        let quantity = self
+            .time_unit
+            .convert_from_bits_per_second(self.size_unit.convert_from_bits(total_bits_per_second));
+        DataRate::new(quantity, self.size_unit, self.time_unit)
+    }
+}
+
+impl AddAssign for DataRate {
+    fn add_assign(&mut self, rhs: DataRate) {
+        *self = *self + rhs;
+    }
+}
+
+impl DataRate {
+    fn bits_per_second(&self) -> f64 {
+        self.time_unit
+            .convert_to_bits_per_second(self.size_unit.convert_to_bits(self.quantity))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConverterError {
+    #[error("Error parsing number")]
+    ParseNumberError,
+    #[error("Could not parse the provided data size unit {0:?}")]
+    DataSizeUnitParseError(String),
+    #[error("Could not parse the provided time unit {0:?}")]
+    TimeUnitParseError(String),
+    //TODO this could provide the actual attempted value and rates?
+    #[error("Could not convert to the requested output unit")]
+    ConversionError,
+    #[error("One or more required input arguments missing")]
+    MissingArguments,
+    #[error("Could not parse {0:?} via Nom({1:?})")]
+    NomError(String, ErrorKind),
+    #[error("I/O error: {0}")]
+    IoError(String),
+}
+
+impl From<std::io::Error> for ConverterError {
+    fn from(value: std::io::Error) -> Self {
+        Self::IoError(value.to_string())
+    }
+}
+
+impl From<ParseIntError> for ConverterError {
+    fn from(_value: ParseIntError) -> Self {
+        Self::ParseNumberError
+    }
+}
+
+impl From<ParseFloatError> for ConverterError {
+    fn from(_value: ParseFloatError) -> Self {
+        Self::ParseNumberError
+    }
+}
+
+impl ParseError<&str> for ConverterError {
+    fn from_error_kind(input: &str, kind: ErrorKind) -> Self {
+        ConverterError::NomError(input.to_owned(), kind)
+    }
+
+    fn append(input: &str, kind: ErrorKind, _other: Self) -> Self {
+        ConverterError::NomError(input.to_owned(), kind)
+    }
+}
+
+impl<'a, T> FromExternalError<&'a str, T> for ConverterError {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _e: T) -> Self {
+        ConverterError::NomError(input.to_owned(), kind)
+    }
+}
+
+/// Parses the size-unit word at the front of `input` (e.g. `pb`, `MiB`), trying
+/// progressively shorter prefixes of the alphabetic run until one matches a known
+/// `DataSizeUnit`. This lets units like `pb`/`eb` (which contain the letter `p`)
+/// coexist with the `p`/`per` separator shorthand handled by `parse_rate_separator`,
+/// instead of banning `p` from size-unit words altogether.
+pub fn parse_data_size_unit(input: &str) -> IResult<&str, DataSizeUnit, ConverterError> {
+    let (_, word) = take_while(|c: char| c.is_ascii_alphabetic())(input)?;
+    for split in (1..=word.len()).rev() {
+        if let Ok(unit) = DataSizeUnit::from_str(&word[..split]) {
+            return Ok((&input[split..], unit));
+        }
+    }
+    Err(nom::Err::Error(ConverterError::DataSizeUnitParseError(
+        word.to_string(),
+    )))
+}
+
+pub fn parse_time_unit(input: &str) -> IResult<&str, TimeUnit, ConverterError> {
+    map_res(take_while(|c: char| c.is_ascii_alphabetic()), |unit| {
+        TimeUnit::from_str(unit)
+    })(input)
+}
+
+/// Parses the separator between the size and time units of a rate: an explicit
+/// `/`, the spelled-out word `per`, or the historical single-letter `p` shorthand
+/// (as in `kbph` = kilobit **p**er **h**our).
+pub fn parse_rate_separator(input: &str) -> IResult<&str, (), ConverterError> {
+    for sep in ["/", "per", "p"] {
+        if let Some(rest) = input.strip_prefix(sep) {
+            return Ok((rest, ()));
+        }
+    }
+    Err(nom::Err::Error(ConverterError::NomError(
+        input.to_string(),
+        ErrorKind::Char,
+    )))
+}
+
+///Parses a string of the form "kb/s" into the size and time units of a rate.
+pub fn parse_data_rate(input: &str) -> IResult<&str, (DataSizeUnit, TimeUnit), ConverterError> {
+    let (input, (_, size_unit, _sep, time_unit)) = tuple((
+        nom::character::complete::space0,
+        parse_data_size_unit,
+        parse_rate_separator,
+        parse_time_unit,
+    ))(input)?;
+    Ok((input, (size_unit, time_unit)))
+}
+
+///Parses a supplied string (e.g. "100 kb/s") into a `DataRate`.
+pub fn parse_input_rate(input: &str) -> IResult<&str, DataRate, ConverterError> {
+    let (input, (_, qty, (size_unit, time_unit))) =
+        tuple((nom::character::complete::space0, double, parse_data_rate))(input)?;
+    Ok((input, DataRate::new(qty, size_unit, time_unit)))
+}
+
+pub fn unwrap_nom_error<T>(
+    nom_res: IResult<&str, T, ConverterError>,
+) -> std::result::Result<(&str, T), ConverterError> {
+    match nom_res {
+        Ok(v) => Ok(v),
+        Err(nom::Err::Error(e)) => Err(e),
+        Err(nom::Err::Failure(e)) => Err(e),
+        Err(nom::Err::Incomplete(_)) => panic!("Nom Incomplete error"),
+    }
+}
+
+pub fn convert_data_rate(rate: &DataRate, target_rate: &DataRate) -> Option<f64> {
+    let value_in_bits = rate.size_unit.convert_to_bits(rate.quantity);
+    let value_in_bits_per_second = rate.time_unit.convert_to_bits_per_second(value_in_bits);
+
+    let converted_value_in_bits = target_rate
+        .size_unit
+        .convert_from_bits(value_in_bits_per_second);
+    let converted_value = target_rate
+        .time_unit
+        .convert_from_bits_per_second(converted_value_in_bits);
+
+    Some(converted_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use DataSizeUnit::*;
+    use TimeUnit::*;
+
+    /// Round an f64 value to a specific number of significant digits
+    fn precision_f64(x: f64, decimals: u32) -> f64 {
+        if x == 0. || decimals == 0 {
+            0.0
+        } else {
+            let shift = decimals as i32 - x.abs().log10().ceil() as i32;
+            let shift_factor = 10_f64.powi(shift);
+
+            (x * shift_factor).round() / shift_factor
+        }
+    }
+
+    #[rstest]
+    #[case::nnn_kb_s("123 kb/s", DataRate::new(123.0, KiloBit, Second))]
+    #[case::nnn_kbph("123 kbph", DataRate::new(123.0, KiloBit, Hour))]
+    #[case::nnnn_mb_hr("1024 MB/hr", DataRate::new(1024.0, MegaByte, Hour))]
+    #[case::nnnn_mib_h("1024 MiB/h", DataRate::new(1024.0, MebiByte, Hour))]
+    #[case::nnnn_mb_hr_no_spaces("1024MB/hr", DataRate::new(1024.0, MegaByte, Hour))]
+    #[case::nnnn_mb_hr_leading_spaces("   1024 MB/hr", DataRate::new(1024.0, MegaByte, Hour))]
+    #[case::zero_b_ms("0 b/ms", DataRate::new(0.0, Bit, Millisecond))]
+    #[case::nnn_point_mmm_kb_d("123.456 kB/d", DataRate::new(123.456, KiloByte, Day))]
+    #[case::zero_point_mmm_kb_d("0.456 kB/d", DataRate::new(0.456, KiloByte, Day))]
+    #[case::point_mmm_kb_d(".456 kB/d", DataRate::new(0.456, KiloByte, Day))]
+    #[case::nnn_point_zero_kb_d("123.0 kB/d", DataRate::new(123.0, KiloByte, Day))]
+    #[case::nnn_pb_s("5 pb/s", DataRate::new(5.0, PetaBit, Second))]
+    #[case::nnn_pb_upper_s("5 PB/s", DataRate::new(5.0, PetaByte, Second))]
+    #[case::nnn_pib_s("5 PiB/s", DataRate::new(5.0, PebiByte, Second))]
+    #[case::nnn_eb_s("5 eb/s", DataRate::new(5.0, ExaBit, Second))]
+    #[case::nnn_eb_upper_s("5 EB/s", DataRate::new(5.0, ExaByte, Second))]
+    #[case::nnn_eib_s("5 EiB/s", DataRate::new(5.0, ExbiByte, Second))]
+    #[case::nnn_gb_week("1 GB/w", DataRate::new(1.0, GigaByte, Week))]
+    #[case::nnn_tb_month("1 TB/month", DataRate::new(1.0, TeraByte, Month))]
+    #[case::nnn_tb_year("1 TB/yr", DataRate::new(1.0, TeraByte, Year))]
+    fn test_parse_data_rate(#[case] input: &str, #[case] expected: DataRate) {
+        let (_, parsed) = parse_input_rate(input).unwrap();
+        assert_eq!(parsed, expected);
+    }
+
+    #[rstest]
+    #[case::invalid_unit_no_digits("abc kb/s")]
+    #[case::missing_per("123 kbs")]
+    #[case::invalid_time_unit("123 kb/abc")]
+    #[case::invalid_value_some_digits("123abc kb/s")]
+    #[case::missing_value("kb/s")]
+    #[case::invalid_per("123 kb s")]
+    #[case::invalid_per_char("123 kbXs")]
+    fn test_parse_data_rate_error(#[case] input: &str) {
+        assert!(parse_input_rate(input).is_err());
+    }
+
+    #[rstest]
+    #[case::kbit_s_to_mbit_s(
+        DataRate::new(1000.0, KiloBit, Second),
+        DataRate::new(0.0, MegaBit, Second),
+        1.0
+    )]
+    #[case::gb_hr_to_gb_s(
+        DataRate::new(1.0, GigaByte, Hour),
+        DataRate::new(0.0, GigaByte, Second),
+        1.0 / 3600.0)]
+    #[case::mib_min_to_gib_hr(
+        DataRate::new(500.0, MebiByte, Minute),
+        DataRate::new(0.0, GibiByte, Hour),
+        500.0*60.0/1024.0
+    )]
+    #[case::mbit_min_to_gbit_hr(
+        DataRate::new(500.0, MegaBit, Minute),
+        DataRate::new(0.0, GigaBit, Hour),
+        30.0
+    )]
+    #[case::kbit_ms_to_kbit_s(
+        DataRate::new(1000.0, KiloBit, Millisecond),
+        DataRate::new(0.0, KiloBit, Second),
+        1_000_000.0
+    )]
+    #[case::byte_day_to_byte_sec(
+        DataRate::new(1.0, Byte, Day),
+        DataRate::new(0.0, Byte, Second),
+        1.0 / (3600.0 * 24.0)
+    )]
+    #[case::tb_month_to_tb_sec(
+        DataRate::new(1.0, TeraByte, Month),
+        DataRate::new(0.0, TeraByte, Second),
+        1.0 / (30.4375 * 3600.0 * 24.0)
+    )]
+    #[case::gb_week_to_gb_day(
+        DataRate::new(7.0, GigaByte, Week),
+        DataRate::new(0.0, GigaByte, Day),
+        1.0
+    )]
+    #[case::gb_year_to_gb_day(
+        DataRate::new(365.25, GigaByte, Year),
+        DataRate::new(0.0, GigaByte, Day),
+        1.0
+    )]
+    fn test_convert_data_rate(
+        #[case] input_rate: DataRate,
+        #[case] output_rate: DataRate,
+        #[case] expected: f64,
+    ) {
+        let result = convert_data_rate(&input_rate, &output_rate).unwrap();
+        // some tests are only accurate to around 6 decimal places due to floating point inaccuracies with very large or small values so we'll round them off a bit before testing equality here
+        let rounded_result = precision_f64(result, 6);
+        let rounded_expect = precision_f64(expected, 6);
+        assert_eq!(rounded_result, rounded_expect);
+    }
+
+    #[test]
+    fn test_decimal_output_format() {
+        let result = convert_data_rate(
+            &DataRate::new(1.0, GigaByte, Hour),
+            &DataRate::new(0.0, GigaByte, Second),
+        )
+        .unwrap();
+
+        assert_eq!(
+            format!("{:.1$}", result, 6),
+            format!("{:.1$}", (1.0 / 3600.0), 6)
+        );
+    }
+
+    #[test]
+    fn test_convert_data_rate_error() {
+        //TODO test something more useful here?
+        assert!(convert_data_rate(
+            &DataRate::new(1000.0, KiloBit, Second),
+            &DataRate::new(0.0, Bit, Hour)
+        )
+        .is_some());
+        assert!(convert_data_rate(
+            &DataRate::new(1000.0, KiloBit, Second),
+            &DataRate::new(0.0, Byte, Hour)
+        )
+        .is_some());
+    }
+
+    #[rstest]
+    #[case::slash("/s", Second)]
+    #[case::single_p_shorthand("ph", Hour)]
+    #[case::spelled_out_per("persec", Second)]
+    fn test_parse_rate_separator_then_time(#[case] input: &str, #[case] expected: TimeUnit) {
+        let (rest, ()) = parse_rate_separator(input).unwrap();
+        assert_eq!(parse_time_unit(rest).unwrap().1, expected);
+    }
+
+    #[test]
+    fn test_parse_units() {
+        assert_eq!(parse_data_size_unit("mb").unwrap().1, DataSizeUnit::MegaBit);
+        assert_eq!(parse_time_unit("s").unwrap().1, TimeUnit::Second);
+        assert!(parse_data_size_unit("abc").is_err());
+        assert!(parse_time_unit("xyz").is_err());
+    }
+
+    #[test]
+    fn test_data_rate_display() {
+        let rate = DataRate::new(1.5, MegaByte, Second);
+        assert_eq!(format!("{}", rate), "1.5 MegaByte/Second");
+        assert_eq!(format!("{:.2}", rate), "1.50 MegaByte/Second");
+    }
+
+    #[test]
+    fn test_data_rate_from_str_round_trips_quantity() {
+        let rate: DataRate = "100 kb/s".parse().unwrap();
+        assert_eq!(rate, DataRate::new(100.0, KiloBit, Second));
+    }
+
+    #[test]
+    fn test_data_rate_mul() {
+        let rate = DataRate::new(10.0, MegaByte, Second) * 2.0;
+        assert_eq!(rate, DataRate::new(20.0, MegaByte, Second));
+
+        let mut scaled = DataRate::new(10.0, MegaByte, Second);
+        scaled *= 2.0;
+        assert_eq!(scaled, DataRate::new(20.0, MegaByte, Second));
+    }
+
+    #[test]
+    fn test_data_rate_add_normalizes_through_bits_per_second() {
+        let sum = DataRate::new(1.0, MegaByte, Second) + DataRate::new(8.0, MegaBit, Second);
+        assert_eq!(sum, DataRate::new(2.0, MegaByte, Second));
+
+        let mut total = DataRate::new(1.0, MegaByte, Second);
+        total += DataRate::new(8.0, MegaBit, Second);
+        assert_eq!(total, DataRate::new(2.0, MegaByte, Second));
+    }
+
+    #[test]
+    fn test_data_rate_convert_to() {
+        let rate = DataRate::new(1.0, GigaByte, Hour);
+        let converted = rate.convert_to(&DataRate::new(0.0, GigaByte, Second));
+        assert_eq!(converted.size_unit(), GigaByte);
+        assert_eq!(converted.time_unit(), Second);
+        assert_eq!(
+            format!("{:.6}", converted.quantity()),
+            format!("{:.6}", 1.0 / 3600.0)
+        );
+    }
+}
@@ -22,337 +22,273 @@
 //! ```bash
 //! rateconv -v "100 kb/s" mb/hr
 //! ```
-// mod error;
-use core::fmt;
+//!
+//! `OUTPUT_RATE` can also omit its size unit (e.g. `/hr`, or left blank) to have
+//! the most readable size unit chosen automatically:
+//!
+//! ```bash
+//! rateconv "8000000 b/s" /s
+//! rateconv --as ibytes "8000000 b/s" /s
+//! ```
+//!
+//! `--transfer` switches to a second mode that computes how long it takes to move a
+//! total amount of data at a given rate, printing the elapsed time decomposed into
+//! day/hour/minute/second/millisecond components:
+//!
+//! ```bash
+//! rateconv --transfer "500 GB" "100 MB/s"
+//! ```
+//!
+//! `--batch` switches to a third mode that converts many rates at once, one per line,
+//! read from stdin or `--input-file`, with results (or per-line parse errors) written
+//! one per line in the same order:
+//!
+//! ```bash
+//! rateconv --batch --input-file rates.txt mb/hr
+//! rateconv --batch --format csv /s < rates.txt
+//! ```
+//!
+//! The conversion engine itself (parsing, units, and `DataRate`) lives in the `rate_conv`
+//! library crate; this binary is just its CLI front end.
 use nom::{
-    bytes::complete::take_while,
     character::complete::{one_of, space0},
-    combinator::map_res,
-    error::{ErrorKind, FromExternalError, ParseError},
     number::complete::double,
     sequence::tuple,
     IResult,
 };
+use rate_conv::{
+    parse_data_size_unit, parse_input_rate, unwrap_nom_error, ConverterError, DataRate,
+    DataSizeUnit, TimeUnit,
+};
 use std::{
-    f64,
-    num::{ParseFloatError, ParseIntError},
+    fs::File,
+    io::{self, BufRead},
     process::ExitCode,
     str::FromStr,
 };
 use structopt::StructOpt;
 
-/// Represents a time unit, such as seconds, milliseconds, or hours
-#[derive(Debug, PartialEq, Clone, Copy)]
-enum TimeUnit {
-    Millisecond,
-    Second,
-    Minute,
-    Hour,
-    Day,
+/// Errors specific to the `rateconv` CLI itself (flag parsing, mode combinations), as
+/// opposed to [`ConverterError`], which is the `rate_conv` library's own public error
+/// type (CLI I/O failures are still carried as its `IoError` variant).
+#[derive(Debug, thiserror::Error)]
+enum CliError {
+    #[error(transparent)]
+    Converter(#[from] ConverterError),
+    #[error("Could not parse the provided size unit family {0:?}")]
+    DataSizeFamilyParseError(String),
+    #[error("Could not parse the provided output format {0:?}")]
+    OutputFormatParseError(String),
+    #[error("--transfer is not supported together with --batch")]
+    BatchTransferUnsupported,
+    #[error("Expected at most {max} positional argument(s), got {actual}")]
+    TooManyArguments { max: usize, actual: usize },
 }
 
-impl FromStr for TimeUnit {
-    type Err = ConverterError;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "ms" => Ok(TimeUnit::Millisecond),
-            "s" | "sec" | "second" => Ok(TimeUnit::Second),
-            "m" | "min" => Ok(TimeUnit::Minute),
-            "h" | "hr" | "hour" => Ok(TimeUnit::Hour),
-            "d" | "day" => Ok(TimeUnit::Day),
-            _ => {
-                println!("argh!!!!!");
-                Err(ConverterError::TimeUnitParseError(s.to_string()))
-            }
-        }
+impl From<std::io::Error> for CliError {
+    fn from(value: std::io::Error) -> Self {
+        ConverterError::from(value).into()
     }
 }
 
-impl fmt::Display for TimeUnit {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let base_unit = match self {
-            TimeUnit::Millisecond => "millisecond",
-            TimeUnit::Second => "second",
-            TimeUnit::Minute => "minute",
-            TimeUnit::Hour => "hour",
-            TimeUnit::Day => "day",
-        };
-        write!(f, "{}", base_unit)
-    }
+/// Selects which ladder of [`DataSizeUnit`]s `canonical_size_unit` should auto-select from.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum DataSizeFamily {
+    /// Decimal (SI) bits: bit, kilobit, megabit, ...
+    Bits,
+    /// Decimal (SI) bytes: byte, kilobyte, megabyte, ...
+    Bytes,
+    /// Binary (IEC) bytes: byte, kibibyte, mebibyte, ...
+    IBytes,
 }
 
-impl TimeUnit {
-    fn convert_to_bits_per_second(&self, value: f64) -> f64 {
-        match self {
-            TimeUnit::Millisecond => value * 1000.0,
-            TimeUnit::Second => value,
-            TimeUnit::Minute => value / 60.0,
-            TimeUnit::Hour => value / 3600.0,
-            TimeUnit::Day => value / (3600.0 * 24.0),
+impl FromStr for DataSizeFamily {
+    type Err = CliError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bits" => Ok(DataSizeFamily::Bits),
+            "bytes" => Ok(DataSizeFamily::Bytes),
+            "ibytes" => Ok(DataSizeFamily::IBytes),
+            _ => Err(CliError::DataSizeFamilyParseError(s.to_string())),
         }
     }
+}
 
-    fn convert_from_bits_per_second(&self, value: f64) -> f64 {
+impl DataSizeFamily {
+    /// The candidate units for this family, ordered smallest to largest.
+    fn units(&self) -> &'static [DataSizeUnit] {
+        use DataSizeUnit::*;
         match self {
-            TimeUnit::Millisecond => value / 1000.0,
-            TimeUnit::Second => value,
-            TimeUnit::Minute => value * 60.0,
-            TimeUnit::Hour => value * 3600.0,
-            TimeUnit::Day => value * (3600.0 * 24.0),
+            DataSizeFamily::Bits => &[Bit, KiloBit, MegaBit, GigaBit, TeraBit, PetaBit, ExaBit],
+            DataSizeFamily::Bytes => {
+                &[Byte, KiloByte, MegaByte, GigaByte, TeraByte, PetaByte, ExaByte]
+            }
+            DataSizeFamily::IBytes => {
+                &[Byte, KibiByte, MebiByte, GibiByte, TebiByte, PebiByte, ExbiByte]
+            }
         }
     }
 }
 
+/// Selects how each converted rate is rendered in `--batch` mode.
 #[derive(Debug, PartialEq, Clone, Copy)]
-enum DataSizeUnit {
-    Bit,
-    KiloBit,
-    MegaBit,
-    GigaBit,
-    TeraBit,
-    Byte,
-    KiloByte,
-    MegaByte,
-    GigaByte,
-    TeraByte,
-    KibiByte,
-    MebiByte,
-    GibiByte,
-    TebiByte,
+enum OutputFormat {
+    /// One human-readable `"Converted rate: ..."` line per input line.
+    Text,
+    /// `input,input_unit,output,output_unit` columns, with a header row.
+    Csv,
 }
 
-impl FromStr for DataSizeUnit {
-    type Err = crate::ConverterError;
+impl FromStr for OutputFormat {
+    type Err = CliError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "b" | "bits" | "Bits" => Ok(DataSizeUnit::Bit),
-            "kb" | "kbits" | "KBits" => Ok(DataSizeUnit::KiloBit),
-            "mb" | "mbits" | "MBits" => Ok(DataSizeUnit::MegaBit),
-            "gb" | "gbits" | "GBits" => Ok(DataSizeUnit::GigaBit),
-            "tb" | "tbits" | "TBits" => Ok(DataSizeUnit::TeraBit),
-            "B" | "bytes" => Ok(DataSizeUnit::Byte),
-            "kB" | "KB" | "kBytes" | "KBytes" => Ok(DataSizeUnit::KiloByte),
-            "mB" | "MB" | "mBytes" | "MBytes" => Ok(DataSizeUnit::MegaByte),
-            "gB" | "GB" | "gBytes" | "GBytes" => Ok(DataSizeUnit::GigaByte),
-            "tB" | "TB" | "tBytes" | "TBytes" => Ok(DataSizeUnit::TeraByte),
-            "kiB" | "KiB" | "kibiBytes" | "KibiBytes" => Ok(DataSizeUnit::KibiByte),
-            "miB" | "MiB" | "mebiBytes" | "MebiBytes" => Ok(DataSizeUnit::MebiByte),
-            "giB" | "GiB" | "gibiBytes" | "GibiBytes" => Ok(DataSizeUnit::GibiByte),
-            "tiB" | "TiB" | "tebiBytes" | "TebiBytes" => Ok(DataSizeUnit::TebiByte),
-            _ => Err(ConverterError::DataSizeUnitParseError(s.to_string())),
+            "text" => Ok(OutputFormat::Text),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(CliError::OutputFormatParseError(s.to_string())),
         }
     }
 }
 
-impl fmt::Display for DataSizeUnit {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let base_unit = match self {
-            DataSizeUnit::Bit => "bit",
-            DataSizeUnit::KiloBit => "kilobit",
-            DataSizeUnit::MegaBit => "megabit",
-            DataSizeUnit::GigaBit => "gigabit",
-            DataSizeUnit::TeraBit => "terabit",
-            DataSizeUnit::Byte => "byte",
-            DataSizeUnit::KiloByte => "kilobyte",
-            DataSizeUnit::MegaByte => "megabyte",
-            DataSizeUnit::GigaByte => "gigabyte",
-            DataSizeUnit::TeraByte => "terabyte",
-            DataSizeUnit::KibiByte => "kibibyte",
-            DataSizeUnit::MebiByte => "mebibyte",
-            DataSizeUnit::GibiByte => "gibibyte",
-            DataSizeUnit::TebiByte => "tebibyte",
-        };
-        write!(f, "{}", base_unit)
-    }
-}
-
-impl DataSizeUnit {
-    fn convert_to_bits(&self, value: f64) -> f64 {
-        match self {
-            DataSizeUnit::Bit => value,
-            DataSizeUnit::KiloBit => value * 1_000.0,
-            DataSizeUnit::MegaBit => value * 1_000_000.0,
-            DataSizeUnit::GigaBit => value * 1_000_000_000.0,
-            DataSizeUnit::TeraBit => value * 1_000_000_000_000.0,
-            DataSizeUnit::Byte => value * 8.0,
-            DataSizeUnit::KiloByte => value * 8_000.0,
-            DataSizeUnit::MegaByte => value * 8_000_000.0,
-            DataSizeUnit::GigaByte => value * 8_000_000_000.0,
-            DataSizeUnit::TeraByte => value * 8_000_000_000_000.0,
-            DataSizeUnit::KibiByte => value * (8 * 1024) as f64,
-            DataSizeUnit::MebiByte => value * (8 * 1024 * 1024) as f64,
-            DataSizeUnit::GibiByte => value * (8f64 * 1024f64 * 1024f64 * 1024f64),
-            DataSizeUnit::TebiByte => value * (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64),
+/// Picks the most readable unit from `family` for a given magnitude, so a caller
+/// never has to guess whether a value should be shown as `8000000 b` or `8 Mb`.
+///
+/// `value_in_bits` is the magnitude to display, already expressed per whatever
+/// time unit the caller intends to show (e.g. bits per hour if the output will
+/// be ".../hr"). Candidates are tried from smallest to largest and the largest
+/// one whose converted value is still `>= 1.0` wins; if every candidate converts
+/// to `< 1.0` (or the value is exactly `0.0`) the smallest (base) unit is used.
+fn canonical_size_unit(value_in_bits: f64, family: &[DataSizeUnit]) -> DataSizeUnit {
+    let mut selected = family[0];
+    for &unit in family {
+        if unit.convert_from_bits(value_in_bits) >= 1.0 {
+            selected = unit;
         }
     }
-
-    fn convert_from_bits(&self, value: f64) -> f64 {
-        match self {
-            DataSizeUnit::Bit => value,
-            DataSizeUnit::KiloBit => value / 1_000.0,
-            DataSizeUnit::MegaBit => value / 1_000_000.0,
-            DataSizeUnit::GigaBit => value / 1_000_000_000.0,
-            DataSizeUnit::TeraBit => value / 1_000_000_000_000.0,
-            DataSizeUnit::Byte => value / 8.0,
-            DataSizeUnit::KiloByte => value / 8_000.0,
-            DataSizeUnit::MegaByte => value / 8_000_000.0,
-            DataSizeUnit::GigaByte => value / 8_000_000_000.0,
-            DataSizeUnit::TeraByte => value / 8_000_000_000_000.0,
-            DataSizeUnit::KibiByte => value / (8 * 1024) as f64,
-            DataSizeUnit::MebiByte => value / (8 * 1024 * 1024) as f64,
-            DataSizeUnit::GibiByte => value / (8f64 * 1024f64 * 1024f64 * 1024f64),
-            DataSizeUnit::TebiByte => value / (8f64 * 1024f64 * 1024f64 * 1024f64 * 1024f64),
-        }
-    }
-}
-
-/// Describes a data rate with a given size and time unit.
-#[derive(Debug, PartialEq)]
-struct DataRate {
-    // quantity: f64,
-    size_unit: DataSizeUnit,
-    time_unit: TimeUnit,
+    selected
 }
 
-impl DataRate {
-    #[allow(dead_code)] //used by tests
-    const fn new(size_unit: DataSizeUnit, time_unit: TimeUnit) -> DataRate {
-        Self {
-            size_unit,
-            time_unit,
-        }
+/// Short label used when rendering a decomposed duration, e.g. `"1hr 23min 20sec"`.
+fn time_unit_short_label(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Millisecond => "ms",
+        TimeUnit::Second => "sec",
+        TimeUnit::Minute => "min",
+        TimeUnit::Hour => "hr",
+        TimeUnit::Day => "day",
+        TimeUnit::Week => "wk",
+        TimeUnit::Month => "mo",
+        TimeUnit::Year => "yr",
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-enum ConverterError {
-    // #[error("Error parsing input rate ")]
-    // ParseInputRateError,
-    #[error("Error parsing number")]
-    ParseNumberError,
-    #[error("Could not parse the provided data size unit {0:?}")]
-    DataSizeUnitParseError(String),
-    #[error("Could not parse the provided time unit {0:?}")]
-    TimeUnitParseError(String),
-    //TODO this could provide the actual attempted value and rates?
-    #[error("Could not convert to the requested output unit")]
-    ConversionError,
-    #[error("One or more required input arguments missing")]
-    MissingArguments,
-    #[error("Could not parse {0:?} via Nom({1:?})")]
-    NomError(String, ErrorKind),
-}
-
-impl From<ParseIntError> for ConverterError {
-    fn from(_value: ParseIntError) -> Self {
-        Self::ParseNumberError
-    }
+/// Parses an `OUTPUT_RATE` that omits a size unit, e.g. `/s` or bare `hr`,
+/// for use with [`canonical_size_unit`] auto-selection.
+fn parse_time_only_rate(input: &str) -> IResult<&str, TimeUnit, ConverterError> {
+    let (input, (_, _, time_unit)) = tuple((
+        space0,
+        nom::combinator::opt(one_of("/")),
+        rate_conv::parse_time_unit,
+    ))(input)?;
+    Ok((input, time_unit))
 }
 
-impl From<ParseFloatError> for ConverterError {
-    fn from(_value: ParseFloatError) -> Self {
-        Self::ParseNumberError
-    }
+///Parses a supplied string (e.g. "500 GB") into a decimal quantity and a `DataSizeUnit`,
+///with no time component, for use by the `--transfer` subsystem.
+fn parse_data_size(input: &str) -> IResult<&str, (f64, DataSizeUnit), ConverterError> {
+    let (input, (_, qty, _, size_unit)) =
+        tuple((space0, double, space0, parse_data_size_unit))(input)?;
+    Ok((input, (qty, size_unit)))
 }
 
-impl ParseError<&str> for ConverterError {
-    fn from_error_kind(input: &str, kind: ErrorKind) -> Self {
-        ConverterError::NomError(input.to_owned(), kind)
-    }
-
-    fn append(input: &str, kind: ErrorKind, _other: Self) -> Self {
-        ConverterError::NomError(input.to_owned(), kind)
-    }
+///Computes how long it takes to move `size_quantity size_unit` of data at `rate`, in seconds.
+fn transfer_time_seconds(size_quantity: f64, size_unit: &DataSizeUnit, rate: &DataRate) -> f64 {
+    let total_bits = size_unit.convert_to_bits(size_quantity);
+    let rate_bits = rate.size_unit().convert_to_bits(rate.quantity());
+    let rate_bits_per_second = rate.time_unit().convert_to_bits_per_second(rate_bits);
+    total_bits / rate_bits_per_second
 }
 
-impl<'a, T> FromExternalError<&'a str, T> for ConverterError {
-    fn from_external_error(input: &'a str, kind: ErrorKind, _e: T) -> Self {
-        ConverterError::NomError(input.to_owned(), kind)
+/// The `TimeUnit`s used to decompose a transfer-time duration, largest to smallest.
+const DURATION_COMPONENTS: [TimeUnit; 5] = [
+    TimeUnit::Day,
+    TimeUnit::Hour,
+    TimeUnit::Minute,
+    TimeUnit::Second,
+    TimeUnit::Millisecond,
+];
+
+///Greedily peels the largest whole `TimeUnit` components off `total_seconds`, rounding
+///only the final (smallest, millisecond) component to `decimal_places`.
+fn decompose_duration(total_seconds: f64, decimal_places: usize) -> Vec<(f64, TimeUnit)> {
+    let mut remaining_seconds = total_seconds;
+    let mut components = Vec::new();
+    let (&last_unit, whole_units) = DURATION_COMPONENTS.split_last().unwrap();
+
+    for unit in whole_units {
+        let seconds_per_unit = unit.convert_from_bits_per_second(1.0);
+        let count = (remaining_seconds / seconds_per_unit).floor();
+        remaining_seconds -= count * seconds_per_unit;
+        if count >= 1.0 {
+            components.push((count, *unit));
+        }
     }
-}
 
-fn parse_data_size_unit(input: &str) -> IResult<&str, DataSizeUnit, ConverterError> {
-    let (input, unit_str) =
-        take_while(|c: char| c.is_ascii_alphabetic() && !(c.eq_ignore_ascii_case(&'p')))(input)?;
-    let unit_val = DataSizeUnit::from_str(unit_str);
-    match unit_val {
-        Ok(valid_unit_val) => Ok((input, valid_unit_val)),
-        Err(e) => Err(nom::Err::Error(e)),
+    let seconds_per_last_unit = last_unit.convert_from_bits_per_second(1.0);
+    let last_value = shift_round(remaining_seconds / seconds_per_last_unit, decimal_places);
+    if last_value != 0.0 || components.is_empty() {
+        components.push((last_value, last_unit));
     }
-}
-
-fn parse_time_unit(input: &str) -> IResult<&str, TimeUnit, ConverterError> {
-    map_res(take_while(|c: char| c.is_ascii_alphabetic()), |unit| {
-        TimeUnit::from_str(unit)
-    })(input)
-}
 
-///Parses a string of the form "kb/s" into a `DataRate` struct
-fn parse_data_rate(input: &str) -> IResult<&str, DataRate, ConverterError> {
-    let (input, (_, size_unit, _per, time_unit)) =
-        tuple((space0, parse_data_size_unit, one_of("p/"), parse_time_unit))(input)?;
-    Ok((
-        input,
-        DataRate {
-            size_unit,
-            time_unit,
-        },
-    ))
+    components
 }
 
-///Parses a supplied string into a decimal quantity and a `DataRate` struct
-fn parse_input_rate(input: &str) -> IResult<&str, (f64, DataRate), ConverterError> {
-    let (input, (_, qty, rate_unit)) = tuple((space0, double, parse_data_rate))(input)?;
-    Ok((input, (qty, rate_unit)))
+/// Rounds `value` to `decimals` decimal places.
+fn shift_round(value: f64, decimals: usize) -> f64 {
+    let factor = 10f64.powi(decimals as i32);
+    (value * factor).round() / factor
 }
 
-fn convert_data_rate(quantity: f64, rate: &DataRate, target_rate: &DataRate) -> Option<f64> {
-    let value_in_bits = rate.size_unit.convert_to_bits(quantity);
-    dbg!(value_in_bits);
-    let value_in_bits_per_second = rate.time_unit.convert_to_bits_per_second(value_in_bits);
-    dbg!(value_in_bits_per_second);
-
-    let converted_value_in_bits = target_rate
-        .size_unit
-        .convert_from_bits(value_in_bits_per_second);
-    dbg!(converted_value_in_bits);
-    let converted_value = target_rate
-        .time_unit
-        .convert_from_bits_per_second(converted_value_in_bits);
-    dbg!(converted_value);
-
-    Some(converted_value)
+///Formats a decomposed duration as e.g. `"1hr 23min 20sec"`. Only the smallest
+///(millisecond) component, which may be fractional, is shown with `decimal_places`;
+///the larger, always-whole components are shown as plain integers.
+fn format_duration(components: &[(f64, TimeUnit)], decimal_places: usize) -> String {
+    components
+        .iter()
+        .map(|(value, unit)| match unit {
+            TimeUnit::Millisecond => format!(
+                "{:.precision$}{}",
+                value,
+                time_unit_short_label(unit),
+                precision = decimal_places
+            ),
+            _ => format!("{}{}", *value as i64, time_unit_short_label(unit)),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
-fn unwrap_nom_error<T>(
-    nom_res: IResult<&str, T, ConverterError>,
-) -> std::result::Result<(&str, T), ConverterError> {
-    match nom_res {
-        Ok(v) => Ok(v),
-        Err(nom::Err::Error(e)) => Err(e),
-        Err(nom::Err::Failure(e)) => Err(e),
-        Err(nom::Err::Incomplete(_)) => panic!("Nom Incomplete error"),
+//TODO move fn onto the enum? also re-use from `format_output` so we don't amange plurals twice
+fn describe_data_rate(rate: &DataRate, plural: bool) -> String {
+    if plural {
+        format!("{}s per {}", rate.size_unit(), rate.time_unit())
+    } else {
+        format!("{} per {}", rate.size_unit(), rate.time_unit())
     }
 }
 
-//TODO move fn onto the enum? also re-use from `format_output` so we don't amange plurals twice
-fn describe_data_rate(rate: &DataRate, plural: bool) -> String {
+///Describes a plain data size (no time component), e.g. for `--transfer` verbose output.
+fn describe_data_size(unit: &DataSizeUnit, plural: bool) -> String {
     if plural {
-        format!("{}s per {}", rate.size_unit, rate.time_unit)
+        format!("{}s", unit)
     } else {
-        format!("{} per {}", rate.size_unit, rate.time_unit)
+        format!("{}", unit)
     }
 }
 
 ///Formats the output based on the defined precision, and the size and time units
-fn format_output(converted_qty: f64, rate: &DataRate, decimal_places: usize) -> String {
-    format!(
-        "{:.precision$} {:?}/{:?}",
-        converted_qty,
-        rate.size_unit,
-        rate.time_unit,
-        precision = decimal_places
-    )
+fn format_output(rate: &DataRate, decimal_places: usize) -> String {
+    format!("{:.precision$}", rate, precision = decimal_places)
 }
 
 /// The command-line arguments for the application
@@ -362,13 +298,45 @@ fn format_output(converted_qty: f64, rate: &DataRate, decimal_places: usize) ->
     about = "Converts data rates e.g. `56 kb/s` between different size and time units"
 )]
 struct Opt {
-    /// The data rate to convert (e.g. 64 kb/s)
-    #[structopt(name = "INPUT_RATE")]
-    input_rate: String,
-
-    /// The desired output size and time units (e.g., mb/sec)
-    #[structopt(name = "OUTPUT_RATE", default_value = "kB/s")]
-    output_rate: String,
+    /// The positional rate arguments: `INPUT_RATE [OUTPUT_RATE]` normally, or just
+    /// `[OUTPUT_RATE]` in `--batch` mode (there's no `INPUT_RATE` there — rates come
+    /// from stdin or `--input-file` instead). A plain `Option<String> INPUT_RATE` field
+    /// followed by `OUTPUT_RATE` won't do here: clap/structopt bind positional
+    /// arguments by declaration order regardless of conditional requiredness, so a lone
+    /// value passed under `--batch` would still land in `INPUT_RATE`, not `OUTPUT_RATE`.
+    /// See `Opt::input_rate` and `Opt::output_rate`, which interpret this based on
+    /// `--batch`. The maximum allowed count also depends on `--batch` (1 vs. 2), which
+    /// `structopt`'s `max_values` can't express as a single static attribute, so a
+    /// stray extra positional argument is rejected by `Opt::max_rate_args` /
+    /// `run_with_opt` at runtime instead of by clap.
+    #[structopt(name = "INPUT_RATE_OR_OUTPUT_RATE")]
+    rate_args: Vec<String>,
+
+    /// The family of size units to auto-select from when OUTPUT_RATE omits a size unit
+    #[structopt(long = "as", default_value = "bytes")]
+    as_family: DataSizeFamily,
+
+    /// Transfer-time mode: interpret INPUT_RATE as a total transfer size (e.g. "500 GB")
+    /// and OUTPUT_RATE as the transfer rate (e.g. "100 MB/s"), and print the time taken
+    /// decomposed into time units (e.g. "1hr 23min 20sec") instead of converting a rate.
+    #[structopt(long = "transfer")]
+    transfer: bool,
+
+    /// Batch mode: convert many rates at once, reading one INPUT_RATE per line from
+    /// stdin (or `--input-file`) and converting each to OUTPUT_RATE. Results are
+    /// printed one per line, in order; lines that fail to parse report their line
+    /// number and error on stderr instead of aborting the run.
+    #[structopt(long = "batch")]
+    batch: bool,
+
+    /// In `--batch` mode, read input rates from this file instead of stdin.
+    #[structopt(long = "input-file")]
+    input_file: Option<String>,
+
+    /// In `--batch` mode, how each result line is rendered: `text` (human-readable) or
+    /// `csv` (`input,input_unit,output,output_unit` columns, with a header row).
+    #[structopt(long = "format", default_value = "text")]
+    format: OutputFormat,
 
     ///Enable verbose output
     #[structopt(short = "v", long = "verbose")]
@@ -379,6 +347,42 @@ struct Opt {
     decimal_places: usize,
 }
 
+impl Opt {
+    /// The `INPUT_RATE` positional argument (e.g. "100 kb/s"); `None` in `--batch` mode,
+    /// where the lone positional argument (if any) is `OUTPUT_RATE` instead.
+    fn input_rate(&self) -> Option<&str> {
+        if self.batch {
+            None
+        } else {
+            self.rate_args.first().map(String::as_str)
+        }
+    }
+
+    /// The desired output size and time units (e.g., `mb/sec`), defaulting to `"kB/s"`
+    /// when omitted. The size unit may itself be omitted (e.g. just `/hr`, or left
+    /// blank) to auto-select the most readable size unit for the converted value; see
+    /// `--as`. This is the first positional argument in `--batch` mode, and the second
+    /// (after `INPUT_RATE`) otherwise.
+    fn output_rate(&self) -> &str {
+        let index = if self.batch { 0 } else { 1 };
+        self.rate_args
+            .get(index)
+            .map(String::as_str)
+            .unwrap_or("kB/s")
+    }
+
+    /// The maximum number of `rate_args` this invocation accepts: just `OUTPUT_RATE`
+    /// (1) in `--batch` mode, since there's no `INPUT_RATE` there, or `INPUT_RATE
+    /// OUTPUT_RATE` (2) otherwise.
+    fn max_rate_args(&self) -> usize {
+        if self.batch {
+            1
+        } else {
+            2
+        }
+    }
+}
+
 fn main() -> ExitCode {
     if let Err(err) = run() {
         eprintln!("Error: {}", err);
@@ -387,36 +391,198 @@ fn main() -> ExitCode {
     ExitCode::SUCCESS
 }
 
-fn run() -> Result<(), ConverterError> {
-    let opt = Opt::from_args(); //aborts program on failure
-    if opt.input_rate.is_empty() || opt.output_rate.is_empty() {
-        //TODO tests for this
-        return Err(ConverterError::MissingArguments);
+fn run() -> Result<(), CliError> {
+    run_with_opt(Opt::from_args()) //aborts program on failure
+}
+
+/// The logic of `run`, taking an already-parsed `Opt` so it can be driven by tests
+/// through real argument parsing (`Opt::from_iter`) rather than only by hand-built
+/// `Opt` values.
+fn run_with_opt(opt: Opt) -> Result<(), CliError> {
+    // Note: a blank OUTPUT_RATE (empty or whitespace-only) is not a missing argument —
+    // it's the documented "auto-select a size unit" form, handled by
+    // `resolve_output_rate`'s own `trim().is_empty()` check.
+
+    if opt.rate_args.len() > opt.max_rate_args() {
+        return Err(CliError::TooManyArguments {
+            max: opt.max_rate_args(),
+            actual: opt.rate_args.len(),
+        });
     }
-    let (_, (data_quantity, input_data_rate)) =
-        unwrap_nom_error(parse_input_rate(&opt.input_rate))?;
 
-    let (_, output_rate) = unwrap_nom_error(parse_data_rate(&opt.output_rate))?;
+    if opt.batch {
+        return run_batch(&opt);
+    }
 
-    let converted_rate = convert_data_rate(data_quantity, &input_data_rate, &output_rate)
-        .ok_or(ConverterError::ConversionError)?;
+    let input_rate_arg = match opt.input_rate() {
+        Some(s) if !s.is_empty() => s,
+        _ => return Err(ConverterError::MissingArguments.into()),
+    };
+
+    if opt.transfer {
+        return run_transfer(&opt, input_rate_arg);
+    }
+
+    let (_, input_rate) = unwrap_nom_error(parse_input_rate(input_rate_arg))?;
+    let output_rate = resolve_output_rate(&input_rate, &opt)?;
+    let converted_rate = input_rate.convert_to(&output_rate);
 
     if opt.verbose {
-        let input_rate_desc = describe_data_rate(&input_data_rate, data_quantity != 1.0);
-        let output_rate_desc = describe_data_rate(&output_rate, converted_rate != 1.0);
+        let input_rate_desc = describe_data_rate(&input_rate, input_rate.quantity() != 1.0);
+        let output_rate_desc =
+            describe_data_rate(&converted_rate, converted_rate.quantity() != 1.0);
 
         println!(
             "{} {} is equivalent to {:.precision$} {}",
-            data_quantity,
+            input_rate.quantity(),
             input_rate_desc,
-            converted_rate,
+            converted_rate.quantity(),
             output_rate_desc,
             precision = opt.decimal_places
         );
     } else {
         println!(
             "Converted rate: {}",
-            format_output(converted_rate, &output_rate, opt.decimal_places)
+            format_output(&converted_rate, opt.decimal_places)
+        );
+    }
+
+    Ok(())
+}
+
+/// Resolves `OUTPUT_RATE` into a concrete target `DataRate` for `input_rate`: either the
+/// size/time units it spells out directly, or, when it omits a size unit, the most
+/// readable size unit for `input_rate`'s magnitude (see `canonical_size_unit`).
+fn resolve_output_rate(input_rate: &DataRate, opt: &Opt) -> Result<DataRate, CliError> {
+    match unwrap_nom_error(rate_conv::parse_data_rate(opt.output_rate())) {
+        Ok((_, (size_unit, time_unit))) => Ok(DataRate::new(0.0, size_unit, time_unit)),
+        Err(_) => {
+            let time_unit = if opt.output_rate().trim().is_empty() {
+                input_rate.time_unit()
+            } else {
+                let (_, time_unit) = unwrap_nom_error(parse_time_only_rate(opt.output_rate()))?;
+                time_unit
+            };
+            let value_in_bits = input_rate
+                .size_unit()
+                .convert_to_bits(input_rate.quantity());
+            let value_in_bits_per_second = input_rate
+                .time_unit()
+                .convert_to_bits_per_second(value_in_bits);
+            let value_in_target_time_unit =
+                time_unit.convert_from_bits_per_second(value_in_bits_per_second);
+            let size_unit = canonical_size_unit(value_in_target_time_unit, opt.as_family.units());
+            Ok(DataRate::new(0.0, size_unit, time_unit))
+        }
+    }
+}
+
+///Handles `--batch` mode: reads one `INPUT_RATE` per line from `--input-file` (or stdin
+///if unset), converts each to `OUTPUT_RATE`, and writes one result per line, in the same
+///order as the input. A line that fails to parse reports its 1-based line number and the
+///parse error on stderr and is skipped, rather than aborting the whole run.
+fn run_batch(opt: &Opt) -> Result<(), CliError> {
+    if opt.transfer {
+        return Err(CliError::BatchTransferUnsupported);
+    }
+
+    let reader: Box<dyn BufRead> = match &opt.input_file {
+        Some(path) => Box::new(io::BufReader::new(File::open(path)?)),
+        None => Box::new(io::BufReader::new(io::stdin())),
+    };
+
+    if opt.format == OutputFormat::Csv {
+        println!("input,input_unit,output,output_unit");
+    }
+
+    // `OUTPUT_RATE` only needs auto-selection (and hence a per-line `resolve_output_rate`
+    // call) when it omits a size unit; when it's fully explicit, the same target applies
+    // to every line, so parse it once up front rather than on every iteration.
+    let explicit_output_rate = unwrap_nom_error(rate_conv::parse_data_rate(opt.output_rate()))
+        .ok()
+        .map(|(_, (size_unit, time_unit))| DataRate::new(0.0, size_unit, time_unit));
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match unwrap_nom_error(parse_input_rate(&line)) {
+            Ok((_, input_rate)) => {
+                let output_rate = match explicit_output_rate {
+                    Some(target) => target,
+                    None => resolve_output_rate(&input_rate, opt)?,
+                };
+                let converted_rate = input_rate.convert_to(&output_rate);
+                print_batch_result(&input_rate, &converted_rate, opt);
+            }
+            Err(err) => eprintln!("Error on line {}: {}", line_number + 1, err),
+        }
+    }
+
+    Ok(())
+}
+
+///Renders one `--batch` result line in the requested `OutputFormat`; `--verbose` expands
+///the `text` format the same way it does in single-rate mode (see `run_with_opt`).
+fn print_batch_result(input_rate: &DataRate, converted_rate: &DataRate, opt: &Opt) {
+    match opt.format {
+        OutputFormat::Text if opt.verbose => {
+            let input_rate_desc = describe_data_rate(input_rate, input_rate.quantity() != 1.0);
+            let output_rate_desc =
+                describe_data_rate(converted_rate, converted_rate.quantity() != 1.0);
+            println!(
+                "{} {} is equivalent to {:.precision$} {}",
+                input_rate.quantity(),
+                input_rate_desc,
+                converted_rate.quantity(),
+                output_rate_desc,
+                precision = opt.decimal_places
+            );
+        }
+        OutputFormat::Text => println!(
+            "Converted rate: {}",
+            format_output(converted_rate, opt.decimal_places)
+        ),
+        OutputFormat::Csv => println!(
+            "{:.precision$},{},{:.precision$},{}",
+            input_rate.quantity(),
+            rate_unit_label(input_rate),
+            converted_rate.quantity(),
+            rate_unit_label(converted_rate),
+            precision = opt.decimal_places
+        ),
+    }
+}
+
+///The `size_unit/time_unit` label used for the `*_unit` columns in `--format csv` output.
+fn rate_unit_label(rate: &DataRate) -> String {
+    format!("{:?}/{:?}", rate.size_unit(), rate.time_unit())
+}
+
+///Handles `--transfer` mode: INPUT_RATE is a total transfer size, OUTPUT_RATE is the
+///transfer rate, and the elapsed time is printed decomposed into `TimeUnit` components.
+fn run_transfer(opt: &Opt, input_rate_arg: &str) -> Result<(), CliError> {
+    let (_, (size_quantity, size_unit)) = unwrap_nom_error(parse_data_size(input_rate_arg))?;
+    let (_, rate) = unwrap_nom_error(parse_input_rate(opt.output_rate()))?;
+
+    let total_seconds = transfer_time_seconds(size_quantity, &size_unit, &rate);
+    let components = decompose_duration(total_seconds, opt.decimal_places);
+
+    if opt.verbose {
+        println!(
+            "Transferring {} {} at {} {} takes {}",
+            size_quantity,
+            describe_data_size(&size_unit, size_quantity != 1.0),
+            rate.quantity(),
+            describe_data_rate(&rate, rate.quantity() != 1.0),
+            format_duration(&components, opt.decimal_places)
+        );
+    } else {
+        println!(
+            "Transfer time: {}",
+            format_duration(&components, opt.decimal_places)
         );
     }
 
@@ -430,134 +596,145 @@ mod tests {
     use DataSizeUnit::*;
     use TimeUnit::*;
 
-    /// Round an f64 value to a specific number of significant digits
-    fn precision_f64(x: f64, decimals: u32) -> f64 {
-        if x == 0. || decimals == 0 {
-            0.0
-        } else {
-            let shift = decimals as i32 - x.abs().log10().ceil() as i32;
-            let shift_factor = 10_f64.powi(shift);
+    #[rstest]
+    #[case::zero(0.0, DataSizeFamily::Bytes, Byte)]
+    #[case::sub_one_falls_back_to_base(4.0, DataSizeFamily::Bytes, Byte)]
+    #[case::exact_kilo(8_000.0, DataSizeFamily::Bytes, KiloByte)]
+    #[case::megabyte_range(8_000_000.0, DataSizeFamily::Bytes, MegaByte)]
+    #[case::bits_family(8_000_000.0, DataSizeFamily::Bits, MegaBit)]
+    #[case::binary_family(8.0 * 1024.0 * 1024.0, DataSizeFamily::IBytes, MebiByte)]
+    fn test_canonical_size_unit(
+        #[case] value_in_bits: f64,
+        #[case] family: DataSizeFamily,
+        #[case] expected: DataSizeUnit,
+    ) {
+        assert_eq!(canonical_size_unit(value_in_bits, family.units()), expected);
+    }
 
-            (x * shift_factor).round() / shift_factor
-        }
+    #[rstest]
+    #[case::slash_prefixed("/hr", Hour)]
+    #[case::bare_unit("s", Second)]
+    #[case::leading_space(" /min", Minute)]
+    fn test_parse_time_only_rate(#[case] input: &str, #[case] expected: TimeUnit) {
+        assert_eq!(parse_time_only_rate(input).unwrap().1, expected);
     }
 
     #[rstest]
-    #[case::nnn_kb_s("123 kb/s", 123.0, DataRate::new(KiloBit, Second))]
-    #[case::nnn_kbph("123 kbph", 123.0, DataRate::new(KiloBit, Hour))]
-    #[case::nnnn_mb_hr("1024 MB/hr", 1024.0, DataRate::new(MegaByte, Hour))]
-    #[case::nnnn_mib_h("1024 MiB/h", 1024.0, DataRate::new(MebiByte, Hour))]
-    #[case::nnnn_mb_hr_no_spaces("1024MB/hr", 1024.0, DataRate::new(MegaByte, Hour))]
-    #[case::nnnn_mb_hr_leading_spaces("   1024 MB/hr", 1024.0, DataRate::new(MegaByte, Hour))]
-    #[case::zero_b_ms("0 b/ms", 0.0, DataRate::new(Bit, Millisecond))]
-    #[case::nnn_point_mmm_kb_d("123.456 kB/d", 123.456, DataRate::new(KiloByte, Day))]
-    #[case::zero_point_mmm_kb_d("0.456 kB/d", 0.456, DataRate::new(KiloByte, Day))]
-    #[case::point_mmm_kb_d(".456 kB/d", 0.456, DataRate::new(KiloByte, Day))]
-    #[case::nnn_point_zero_kb_d("123.0 kB/d", 123.0, DataRate::new(KiloByte, Day))]
-    fn test_parse_data_rate(#[case] input: &str, #[case] qty: f64, #[case] rate: DataRate) {
-        let (_, parsed_rate) = parse_input_rate(input).unwrap();
-        assert_eq!(parsed_rate.0, qty);
-        assert_eq!(parsed_rate.1, rate);
+    #[case::nnn_gb("500 GB", 500.0, GigaByte)]
+    #[case::nnn_mb_no_space("100MB", 100.0, MegaByte)]
+    #[case::decimal_tb("1.5 TB", 1.5, TeraByte)]
+    fn test_parse_data_size(#[case] input: &str, #[case] qty: f64, #[case] unit: DataSizeUnit) {
+        let (_, (parsed_qty, parsed_unit)) = parse_data_size(input).unwrap();
+        assert_eq!(parsed_qty, qty);
+        assert_eq!(parsed_unit, unit);
     }
 
     #[rstest]
-    #[case::invalid_unit_no_digits("abc kb/s")]
-    #[case::missing_per("123 kbs")]
-    #[case::invalid_time_unit("123 kb/abc")]
-    #[case::invalid_value_some_digits("123abc kb/s")]
-    #[case::missing_value("kb/s")]
-    #[case::invalid_per("123 kb s")]
-    #[case::invalid_per_char("123 kbXs")]
-    fn test_parse_data_rate_error(#[case] input: &str) {
-        assert!(parse_input_rate(input).is_err());
+    #[case::gb_at_gb_s(500.0, GigaByte, DataRate::new(100.0, MegaByte, Second), 5000.0)]
+    #[case::kb_at_kb_ms(1000.0, KiloByte, DataRate::new(1.0, KiloByte, Millisecond), 1.0)]
+    fn test_transfer_time_seconds(
+        #[case] size_quantity: f64,
+        #[case] size_unit: DataSizeUnit,
+        #[case] rate: DataRate,
+        #[case] expected_seconds: f64,
+    ) {
+        let seconds = transfer_time_seconds(size_quantity, &size_unit, &rate);
+        assert_eq!(seconds, expected_seconds);
+    }
+
+    #[test]
+    fn test_decompose_duration() {
+        let components = decompose_duration(5000.0, 2);
+        assert_eq!(
+            components,
+            vec![(1.0, Hour), (23.0, Minute), (20.0, Second)]
+        );
+        assert_eq!(format_duration(&components, 2), "1hr 23min 20sec");
+    }
+
+    #[test]
+    fn test_decompose_duration_with_fractional_millisecond() {
+        let components = decompose_duration(3.17, 0);
+        assert_eq!(format_duration(&components, 0), "3sec 170ms");
+    }
+
+    #[test]
+    fn test_decompose_duration_zero() {
+        let components = decompose_duration(0.0, 2);
+        assert_eq!(components, vec![(0.0, Millisecond)]);
+        assert_eq!(format_duration(&components, 2), "0.00ms");
+    }
+
+    /// Builds an `Opt` with the given `output_rate`/`as_family`, leaving the rest at
+    /// their non-batch defaults, for exercising `resolve_output_rate` directly.
+    fn opt_with_output_rate(output_rate: &str, as_family: DataSizeFamily) -> Opt {
+        Opt {
+            rate_args: vec![String::new(), output_rate.to_string()],
+            as_family,
+            transfer: false,
+            batch: false,
+            input_file: None,
+            format: OutputFormat::Text,
+            verbose: false,
+            decimal_places: 2,
+        }
     }
 
     #[rstest]
-    #[case::kbit_s_to_mbit_s(
-        1000.0,
-        DataRate::new(KiloBit, Second),
-        DataRate::new(MegaBit, Second),
-        1.0
-    )]
-    #[case::gb_hr_to_gb_s(
-        1.0,
-        DataRate::new(GigaByte, Hour),
-        DataRate::new(GigaByte, Second),
-        1.0 / 3600.0)]
-    #[case::mib_min_to_gib_hr(
-        500.0,
-        DataRate::new(MebiByte, Minute),
-        DataRate::new(GibiByte, Hour),
-        500.0*60.0/1024.0
-    )]
-    #[case::mbit_min_to_gbit_hr(
-        500.0,
-        DataRate::new(MegaBit, Minute),
-        DataRate::new(GigaBit, Hour),
-        30.0
+    #[case::batch_single_positional_is_output_rate(
+        &["rateconv", "--batch", "mb/hr"], None, "mb/hr"
     )]
-    #[case::kbit_ms_to_kbit_s(
-        1000.0,
-        DataRate::new(KiloBit, Millisecond),
-        DataRate::new(KiloBit, Second),
-        1_000_000.0
+    #[case::non_batch_two_positionals(
+        &["rateconv", "100 kb/s", "mb/hr"], Some("100 kb/s"), "mb/hr"
     )]
-    #[case::byte_day_to_byte_sec(
-        1.0,
-        DataRate::new(Byte, Day),
-        DataRate::new(Byte, Second),
-        1.0 / (3600.0 * 24.0)
+    #[case::non_batch_output_rate_defaults_when_omitted(
+        &["rateconv", "100 kb/s"], Some("100 kb/s"), "kB/s"
     )]
-    fn test_convert_data_rate(
-        #[case] qty: f64,
-        #[case] input_rate: DataRate,
-        #[case] output_rate: DataRate,
-        #[case] expected: f64,
+    fn test_opt_positional_args_bind_correctly(
+        #[case] args: &[&str],
+        #[case] expected_input_rate: Option<&str>,
+        #[case] expected_output_rate: &str,
     ) {
-        let result = convert_data_rate(qty, &input_rate, &output_rate).unwrap();
-        // some tests are only accurate to around 6 decimal places due to floating point inaccuracies with very large or small values so we'll round them off a bit before testing equality here
-        let rounded_result = precision_f64(result, 6);
-        let rounded_expect = precision_f64(expected, 6);
-        assert_eq!(rounded_result, rounded_expect);
+        let opt = Opt::from_iter(args);
+        assert_eq!(opt.input_rate(), expected_input_rate);
+        assert_eq!(opt.output_rate(), expected_output_rate);
     }
 
-    #[test]
-    fn test_decimal_output_format() {
-        let result = convert_data_rate(
-            1.0,
-            &DataRate::new(GigaByte, Hour),
-            &DataRate::new(GigaByte, Second),
-        )
-        .unwrap();
+    #[rstest]
+    #[case::non_batch_extra_positional(&["rateconv", "100 kb/s", "mb/hr", "extra_garbage"])]
+    #[case::batch_extra_positional(&["rateconv", "--batch", "mb/hr", "extra_garbage"])]
+    fn test_extra_positional_arg_is_rejected(#[case] args: &[&str]) {
+        let opt = Opt::from_iter(args);
+        assert!(run_with_opt(opt).is_err());
+    }
 
-        assert_eq!(
-            format!("{:.1$}", result, 6),
-            format!("{:.1$}", (1.0 / 3600.0), 6)
-        );
+    #[test]
+    fn test_resolve_output_rate_explicit_units() {
+        let opt = opt_with_output_rate("MB/hr", DataSizeFamily::Bytes);
+        let resolved = resolve_output_rate(&DataRate::new(1.0, KiloByte, Second), &opt).unwrap();
+        assert_eq!(resolved.size_unit(), MegaByte);
+        assert_eq!(resolved.time_unit(), Hour);
     }
 
     #[test]
-    fn test_convert_data_rate_error() {
-        //TODO test something more useful here?
-        assert!(convert_data_rate(
-            1000.0,
-            &DataRate::new(KiloBit, Second),
-            &DataRate::new(Bit, Hour)
-        )
-        .is_some());
-        assert!(convert_data_rate(
-            1000.0,
-            &DataRate::new(KiloBit, Second),
-            &DataRate::new(Byte, Hour)
-        )
-        .is_some());
+    fn test_resolve_output_rate_auto_selects_size_unit() {
+        let opt = opt_with_output_rate("/s", DataSizeFamily::Bits);
+        let resolved = resolve_output_rate(&DataRate::new(8.0, MegaByte, Second), &opt).unwrap();
+        assert_eq!(resolved.size_unit(), MegaBit);
+        assert_eq!(resolved.time_unit(), Second);
     }
 
     #[test]
-    fn test_parse_units() {
-        assert_eq!(parse_data_size_unit("mb").unwrap().1, DataSizeUnit::MegaBit);
-        assert_eq!(parse_time_unit("s").unwrap().1, TimeUnit::Second);
-        assert!(parse_data_size_unit("abc").is_err());
-        assert!(parse_time_unit("xyz").is_err());
+    fn test_rate_unit_label() {
+        let rate = DataRate::new(1.5, MegaByte, Second);
+        assert_eq!(rate_unit_label(&rate), "MegaByte/Second");
+    }
+
+    #[rstest]
+    #[case::empty_string("")]
+    #[case::whitespace_only(" ")]
+    fn test_run_auto_selects_size_unit_for_blank_output_rate(#[case] blank_output_rate: &str) {
+        let opt = Opt::from_iter(&["rateconv", "8000000 b/s", blank_output_rate]);
+        assert!(run_with_opt(opt).is_ok());
     }
 }